@@ -1,8 +1,10 @@
 //! Contains structs, enums and implementations for the main Bunny.net API
 
+use futures::Stream;
 use url::Url;
 
 use crate::error::Error;
+use crate::retry::RetryConfig;
 use reqwest::{
     Client as RClient,
     header::{HeaderMap, HeaderValue},
@@ -21,6 +23,7 @@ pub use region::Region;
 #[derive(Debug, Clone)]
 pub struct BunnyClient {
     reqwest: RClient,
+    retry: RetryConfig,
 }
 
 impl BunnyClient {
@@ -46,9 +49,29 @@ impl BunnyClient {
 
         Ok(Self {
             reqwest,
+            retry: RetryConfig::default(),
         })
     }
 
+    /// Overrides the [`RetryConfig`] used for every request this client makes.
+    ///
+    /// ```
+    /// use bunny_api_tokio::{BunnyClient, RetryConfig, error::Error};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Error> {
+    ///     let mut client = BunnyClient::new("api_key")
+    ///         .await?
+    ///         .with_retry(RetryConfig { max_retries: 5, ..Default::default() });
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+
     // TODO: Following functions could probably use better naming, the names are currently derived from the titles on the API reference
 
     /// Returns a list of countries and tax rates
@@ -68,12 +91,12 @@ impl BunnyClient {
     /// }
     /// ```
     pub async fn get_country_list(&self) -> Result<Vec<Country>, Error> {
-        let response = self
+        let request = self
             .reqwest
             .get("https://api.bunny.net/country")
-            .header("accept", "application/json")
-            .send()
-            .await?;
+            .header("accept", "application/json");
+
+        let response = self.retry.send(request, true).await?;
 
         if response.status().as_u16() == 401 {
             return Err(Error::Authentication(response.text().await?));
@@ -105,12 +128,12 @@ impl BunnyClient {
         page: i32,
         per_page: i32,
     ) -> Result<Pagination<ApiKey>, Error> {
-        let response = self
+        let request = self
             .reqwest
             .get("https://api.bunny.net/apikey")
-            .query(&[("page", page), ("perPage", per_page)])
-            .send()
-            .await?;
+            .query(&[("page", page), ("perPage", per_page)]);
+
+        let response = self.retry.send(request, true).await?;
 
         if response.status().as_u16() == 401 {
             return Err(Error::Authentication(response.text().await?));
@@ -121,6 +144,42 @@ impl BunnyClient {
         Ok(response.json().await?)
     }
 
+    /// Returns every API Key as a lazily-paginated [`Stream`], fetching the
+    /// next page only when the current one drains.
+    ///
+    /// This removes the manual `current_page`/`has_more_items` bookkeeping that
+    /// [`list_api_keys`](Self::list_api_keys) leaves to the caller; `per_page`
+    /// controls how many keys are requested per underlying call.
+    ///
+    /// ```no_run
+    /// use bunny_api_tokio::{BunnyClient, error::Error};
+    /// use futures::StreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Error> {
+    ///     let client = BunnyClient::new("api_key").await?;
+    ///
+    ///     let mut keys = client.list_api_keys_stream(1000);
+    ///
+    ///     while let Some(key) = keys.next().await {
+    ///         println!("{:#?}", key?);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn list_api_keys_stream(
+        &self,
+        per_page: i32,
+    ) -> impl Stream<Item = Result<ApiKey, Error>> {
+        let client = self.clone();
+
+        pagination::paginate(move |page| {
+            let client = client.clone();
+            async move { client.list_api_keys(page, per_page).await }
+        })
+    }
+
     /// Returns a list of Regions
     ///
     /// ```
@@ -138,11 +197,9 @@ impl BunnyClient {
     /// }
     /// ```
     pub async fn region_list(&self) -> Result<Vec<Region>, Error> {
-        let response = self
-            .reqwest
-            .get("https://api.bunny.net/region")
-            .send()
-            .await?;
+        let request = self.reqwest.get("https://api.bunny.net/region");
+
+        let response = self.retry.send(request, true).await?;
 
         if response.status().as_u16() == 401 {
             return Err(Error::Authentication(response.text().await?));
@@ -169,15 +226,15 @@ impl BunnyClient {
     /// }
     /// ```
     pub async fn purge_url(&self, url: Url, asynchronous: bool) -> Result<(), Error> {
-        let response = self
+        let request = self
             .reqwest
             .post("https://api.bunny.net/purge")
             .query(&[
                 ("url", url.to_string()),
                 ("async", asynchronous.to_string()),
-            ])
-            .send()
-            .await?;
+            ]);
+
+        let response = self.retry.send(request, false).await?;
 
         if response.status().as_u16() == 401 {
             return Err(Error::Authentication(response.text().await?));
@@ -1,5 +1,11 @@
+use std::collections::VecDeque;
+use std::future::Future;
+
+use futures::Stream;
 use serde::Deserialize;
 
+use crate::error::Error;
+
 /// Pagination struct used by Bunny.net API
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "PascalCase")]
@@ -13,3 +19,45 @@ pub struct Pagination<T> {
     /// Has more items
     pub has_more_items: bool,
 }
+
+/// Drives a paginated endpoint as a lazy [`Stream`], fetching the next page
+/// only once the current page's items have been consumed.
+///
+/// `fetch` is handed a 1-based page number and returns that page; continuation
+/// is driven by [`Pagination::has_more_items`], so the stream terminates when
+/// the last page reports no further items.
+pub(crate) fn paginate<T, F, Fut>(fetch: F) -> impl Stream<Item = Result<T, Error>>
+where
+    F: Fn(i32) -> Fut,
+    Fut: Future<Output = Result<Pagination<T>, Error>>,
+{
+    let state = PageState {
+        next_page: 1,
+        buffer: VecDeque::new(),
+        exhausted: false,
+    };
+
+    futures::stream::try_unfold((state, fetch), move |(mut state, fetch)| async move {
+        loop {
+            if let Some(item) = state.buffer.pop_front() {
+                return Ok(Some((item, (state, fetch))));
+            }
+
+            if state.exhausted {
+                return Ok(None);
+            }
+
+            let page = fetch(state.next_page).await?;
+            state.exhausted = !page.has_more_items;
+            state.next_page += 1;
+            state.buffer.extend(page.items);
+        }
+    })
+}
+
+/// Continuation state threaded through [`paginate`]'s [`Stream`].
+struct PageState<T> {
+    next_page: i32,
+    buffer: VecDeque<T>,
+    exhausted: bool,
+}
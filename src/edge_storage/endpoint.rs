@@ -24,6 +24,53 @@ pub enum Endpoint {
     Sydney,
     /// Lets you input a custom endpoint, in case bunny adds a new one and this crate isnt up-to-date, has to be a valid URL with http(s) in front
     Custom(String),
+    /// A custom base with an explicit addressing style, for self-hosted or
+    /// proxied gateways. With `path_style` the zone is composed as
+    /// `{base}/{zone}/`, otherwise host-style as `{zone}.{base}/`.
+    CustomWithStyle {
+        /// Base URL of the gateway, e.g. `https://storage.example.com`
+        base: Url,
+        /// Whether to address the zone path-style (`true`) or host-style (`false`)
+        path_style: bool,
+    },
+}
+
+impl Endpoint {
+    /// Composes the full storage-zone base URL for this endpoint.
+    ///
+    /// Regional and [`Custom`](Self::Custom) endpoints are addressed path-style
+    /// as `{host}/{zone}/`, with the zone at the root of the host (any path in a
+    /// `Custom` URL is replaced); [`CustomWithStyle`](Self::CustomWithStyle) honours
+    /// its `path_style` flag, producing `{zone}.{base}/` when host-style.
+    pub fn resolve<T: AsRef<str>>(self, storage_zone: T) -> Result<Url, Error> {
+        let zone = storage_zone.as_ref().trim_matches('/');
+
+        match self {
+            Endpoint::CustomWithStyle { base, path_style } => {
+                if path_style {
+                    let mut base = base;
+                    if !base.path().ends_with('/') {
+                        let path = format!("{}/", base.path());
+                        base.set_path(&path);
+                    }
+                    Ok(base.join(&format!("{zone}/"))?)
+                } else {
+                    let mut url = base;
+                    let host = url
+                        .host_str()
+                        .ok_or_else(|| Error::BadRequest(String::from("endpoint base URL has no host")))?
+                        .to_string();
+                    url.set_host(Some(&format!("{zone}.{host}")))?;
+                    url.set_path("/");
+                    Ok(url)
+                }
+            }
+            endpoint => {
+                let base: Url = endpoint.try_into()?;
+                Ok(base.join(&format!("/{zone}/"))?)
+            }
+        }
+    }
 }
 
 impl TryInto<Url> for Endpoint {
@@ -41,6 +88,7 @@ impl TryInto<Url> for Endpoint {
             Endpoint::Johannesburg => Ok(Url::parse("https://jh.storage.bunnycdn.com")?),
             Endpoint::Sydney => Ok(Url::parse("https://syd.storage.bunnycdn.com")?),
             Endpoint::Custom(url) => Ok(Url::parse(&url)?),
+            Endpoint::CustomWithStyle { base, .. } => Ok(base),
         }
     }
 }
@@ -2,21 +2,35 @@
 //! 
 //! Contains enums, structs and functions for the Bunny Edge Storage API
 
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
 use crate::error::Error;
+use crate::retry::RetryConfig;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
 use bytes::Bytes;
-use reqwest::{header::{HeaderMap, HeaderValue}, Client};
+use futures::{Stream, StreamExt, TryStream, TryStreamExt};
+use reqwest::{header::{HeaderMap, HeaderValue}, Body, Client};
+use sha2::{Digest, Sha256};
+use tokio::sync::Semaphore;
 use url::Url;
 
 mod endpoint;
 pub use endpoint::Endpoint;
 mod list_file;
 pub use list_file::ListFile;
+#[cfg(feature = "object_store")]
+mod object_store;
 
 /// Edge Storage API for bunny
 #[derive(Debug, Clone)]
 pub struct EdgeStorageClient {
     pub(crate) url: Url,
     pub(crate) reqwest: Client,
+    pub(crate) retry: RetryConfig,
+    pub(crate) pull_zone: Option<Url>,
 }
 
 impl<'a> EdgeStorageClient {
@@ -39,17 +53,142 @@ impl<'a> EdgeStorageClient {
 
         let reqwest = Client::builder().default_headers(headers).build()?;
 
-        let endpoint: Url = endpoint.try_into()?;
-        let storage_zone = String::from("/") + storage_zone.as_ref() + "/";
-
-        let url = endpoint.join(&storage_zone)?;
+        let url = endpoint.resolve(storage_zone)?;
 
         Ok(Self {
             url,
             reqwest,
+            retry: RetryConfig::default(),
+            pull_zone: None,
         })
     }
 
+    /// Returns the resolved base URL the zone is addressed through, as composed
+    /// from the [`Endpoint`] and storage zone at construction.
+    pub fn base_url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Sets the pull-zone base URL (for example `https://myzone.b-cdn.net`) that
+    /// [`signed_url`](Self::signed_url) builds shareable links against.
+    ///
+    /// This is distinct from the Edge Storage API host: public links are served
+    /// by the pull zone, which does not require the `AccessKey` header.
+    ///
+    /// ```no_run
+    /// use bunny_api_tokio::{EdgeStorageClient, error::Error, edge_storage::Endpoint};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Error> {
+    ///     let client = EdgeStorageClient::new("storage_zone_api_key", Endpoint::Frankfurt, "MyStorageZone")
+    ///         .await?
+    ///         .with_pull_zone("https://myzone.b-cdn.net".parse()?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_pull_zone(mut self, pull_zone: Url) -> Self {
+        self.pull_zone = Some(pull_zone);
+        self
+    }
+
+    /// Generates a time-limited signed URL for an object using Bunny's token
+    /// authentication, letting callers hand out temporary links without
+    /// exposing the API key.
+    ///
+    /// The link is built against the pull-zone base URL configured with
+    /// [`with_pull_zone`](Self::with_pull_zone) — not the Edge Storage API
+    /// host — so the result is a public link the pull zone serves without the
+    /// `AccessKey` header. Returns [`Error::BadRequest`] if no pull zone has
+    /// been configured.
+    ///
+    /// The token is `SHA256(security_key + path + expiration)` where `path` is
+    /// the decoded object path (the representation Bunny's edge signs, not the
+    /// percent-encoded form) and `expiration` is the absolute Unix timestamp
+    /// (`now + expires_in`), Base64-encoded and made URL-safe (`+`→`-`,
+    /// `/`→`_`, padding stripped). The resulting `?token=…&expires=…` query is
+    /// appended to the pull-zone URL.
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use bunny_api_tokio::{EdgeStorageClient, error::Error, edge_storage::Endpoint};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Error> {
+    ///     let client = EdgeStorageClient::new("storage_zone_api_key", Endpoint::Frankfurt, "MyStorageZone")
+    ///         .await?
+    ///         .with_pull_zone("https://myzone.b-cdn.net".parse()?);
+    ///
+    ///     let url = client.signed_url("/images/file.png", Duration::from_secs(3600), "security_key")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn signed_url<T: AsRef<str>>(
+        &self,
+        path: T,
+        expires_in: Duration,
+        security_key: &str,
+    ) -> Result<Url, Error> {
+        let pull_zone = self.pull_zone.as_ref().ok_or_else(|| {
+            Error::BadRequest(String::from(
+                "no pull zone configured; call with_pull_zone before signing URLs",
+            ))
+        })?;
+
+        // Bunny signs the decoded path, so hash the caller-supplied path
+        // (normalised to a leading slash) rather than the percent-encoded form
+        // that ends up in the URL.
+        let object_path = path.as_ref();
+        let signed_path = if object_path.starts_with('/') {
+            object_path.to_string()
+        } else {
+            format!("/{object_path}")
+        };
+
+        let expires = (SystemTime::now() + expires_in)
+            .duration_since(UNIX_EPOCH)
+            .expect("system time is after the Unix epoch")
+            .as_secs();
+
+        let mut hasher = Sha256::new();
+        hasher.update(security_key.as_bytes());
+        hasher.update(signed_path.as_bytes());
+        hasher.update(expires.to_string().as_bytes());
+
+        let token = STANDARD
+            .encode(hasher.finalize())
+            .replace('+', "-")
+            .replace('/', "_")
+            .replace('=', "");
+
+        let mut url = pull_zone.join(&signed_path)?;
+        url.query_pairs_mut()
+            .append_pair("token", &token)
+            .append_pair("expires", &expires.to_string());
+
+        Ok(url)
+    }
+
+    /// Overrides the [`RetryConfig`] used for every request this client makes.
+    ///
+    /// ```no_run
+    /// use bunny_api_tokio::{EdgeStorageClient, RetryConfig, error::Error, edge_storage::Endpoint};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Error> {
+    ///     let client = EdgeStorageClient::new("storage_zone_api_key", Endpoint::Frankfurt, "MyStorageZone")
+    ///         .await?
+    ///         .with_retry(RetryConfig { max_retries: 5, ..Default::default() });
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+
     /// Uploads a file to the Storage Zone
     ///
     /// ```
@@ -71,13 +210,81 @@ impl<'a> EdgeStorageClient {
     /// }
     /// ```
     pub async fn upload<T: AsRef<str>>(&self, path: T, file: Bytes) -> Result<(), Error> {
-        let response = self
+        let checksum = sha256_hex(&file);
+
+        let request = self
             .reqwest
             .put(self.url.join(path.as_ref())?)
             .header("Content-Type", "application/octet-stream")
-            .body(file)
-            .send()
-            .await?;
+            .header("Checksum", &checksum)
+            .body(file);
+
+        let response = self.retry.send(request, false).await?;
+
+        if response.status().as_u16() == 401 {
+            return Err(Error::Authentication(response.text().await?));
+        } else if response.status().as_u16() == 400 {
+            return Err(Error::BadRequest(response.text().await?));
+        }
+
+        Ok(())
+    }
+
+    /// Uploads a file to the Storage Zone by streaming its contents, without
+    /// buffering the whole payload in memory.
+    ///
+    /// The `stream` is fed directly into the request body, so callers can pipe
+    /// from a [`tokio::fs::File`] (via [`tokio_util::io::ReaderStream`]) or any
+    /// other [`futures::Stream`] of [`Bytes`]. When `content_length` is known it
+    /// is sent as the `Content-Length` header, otherwise the body is sent
+    /// chunked.
+    ///
+    /// Unlike [`upload`](Self::upload), this variant sends no `Checksum` header:
+    /// the header has to precede the body on the wire, so the digest cannot be
+    /// known before the stream has been consumed. Streaming uploads are
+    /// therefore not checksum-verified — use [`upload`](Self::upload) when
+    /// server-side integrity checking is required.
+    ///
+    /// ```no_run
+    /// use bunny_api_tokio::{EdgeStorageClient, error::Error, edge_storage::Endpoint};
+    /// use tokio::fs::File;
+    /// use tokio_util::io::ReaderStream;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Error> {
+    ///     let client = EdgeStorageClient::new("storage_zone_api_key", Endpoint::Frankfurt, "MyStorageZone").await?;
+    ///
+    ///     let file = File::open("path/to/file.png").await.unwrap();
+    ///     let length = file.metadata().await.unwrap().len();
+    ///
+    ///     client.upload_stream("/images/file.png", ReaderStream::new(file), Some(length)).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn upload_stream<T, S>(
+        &self,
+        path: T,
+        stream: S,
+        content_length: Option<u64>,
+    ) -> Result<(), Error>
+    where
+        T: AsRef<str>,
+        S: TryStream + Send + Sync + 'static,
+        S::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+        Bytes: From<S::Ok>,
+    {
+        let mut request = self
+            .reqwest
+            .put(self.url.join(path.as_ref())?)
+            .header("Content-Type", "application/octet-stream")
+            .body(Body::wrap_stream(stream));
+
+        if let Some(content_length) = content_length {
+            request = request.header("Content-Length", content_length);
+        }
+
+        let response = self.retry.send(request, false).await?;
 
         if response.status().as_u16() == 401 {
             return Err(Error::Authentication(response.text().await?));
@@ -111,12 +318,12 @@ impl<'a> EdgeStorageClient {
     /// }
     /// ```
     pub async fn download<T: AsRef<str>>(&self, path: T) -> Result<Bytes, Error> {
-        let response = self
+        let request = self
             .reqwest
             .get(self.url.join(path.as_ref())?)
-            .header("accept", "*/*")
-            .send()
-            .await?;
+            .header("accept", "*/*");
+
+        let response = self.retry.send(request, true).await?;
 
         if response.status().as_u16() == 401 {
             return Err(Error::Authentication(response.text().await?));
@@ -127,6 +334,185 @@ impl<'a> EdgeStorageClient {
         Ok(response.bytes().await?)
     }
 
+    /// Downloads a file and verifies its integrity against the SHA256
+    /// `Checksum` header reported by the server, guarding against silent
+    /// corruption on large transfers.
+    ///
+    /// Returns [`Error::ChecksumMismatch`] when the digest of the received
+    /// bytes does not match the server-reported checksum. Servers that do not
+    /// return a `Checksum` header skip verification and the bytes are returned
+    /// unchanged.
+    ///
+    /// ```no_run
+    /// use bunny_api_tokio::{EdgeStorageClient, error::Error, edge_storage::Endpoint};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Error> {
+    ///     let client = EdgeStorageClient::new("storage_zone_api_key", Endpoint::Frankfurt, "MyStorageZone").await?;
+    ///
+    ///     let contents = client.download_verified("/images/file.png").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn download_verified<T: AsRef<str>>(&self, path: T) -> Result<Bytes, Error> {
+        let request = self
+            .reqwest
+            .get(self.url.join(path.as_ref())?)
+            .header("accept", "*/*");
+
+        let response = self.retry.send(request, true).await?;
+
+        if response.status().as_u16() == 401 {
+            return Err(Error::Authentication(response.text().await?));
+        } else if response.status().as_u16() == 404 {
+            return Err(Error::NotFound(response.text().await?));
+        }
+
+        let expected = response
+            .headers()
+            .get("Checksum")
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_uppercase());
+
+        let bytes = response.bytes().await?;
+
+        if let Some(expected) = expected {
+            let actual = sha256_hex(&bytes);
+            if actual != expected {
+                return Err(Error::ChecksumMismatch { expected, actual });
+            }
+        }
+
+        Ok(bytes)
+    }
+
+    /// Downloads a file from the Storage Zone as a stream of [`Bytes`] chunks,
+    /// without materializing the whole object in memory.
+    ///
+    /// The returned stream yields the response body as it arrives, so callers
+    /// can pipe it straight to a file or onward network connection.
+    ///
+    /// ```no_run
+    /// use bunny_api_tokio::{EdgeStorageClient, error::Error, edge_storage::Endpoint};
+    /// use futures::StreamExt;
+    /// use tokio::fs::File;
+    /// use tokio::io::AsyncWriteExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Error> {
+    ///     let client = EdgeStorageClient::new("storage_zone_api_key", Endpoint::Frankfurt, "MyStorageZone").await?;
+    ///
+    ///     let mut stream = client.download_stream("/images/file.png").await?;
+    ///     let mut file = File::create("file.png").await.unwrap();
+    ///
+    ///     while let Some(chunk) = stream.next().await {
+    ///         file.write_all(&chunk?).await.unwrap();
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn download_stream<T: AsRef<str>>(
+        &self,
+        path: T,
+    ) -> Result<impl Stream<Item = Result<Bytes, Error>>, Error> {
+        let request = self
+            .reqwest
+            .get(self.url.join(path.as_ref())?)
+            .header("accept", "*/*");
+
+        let response = self.retry.send(request, true).await?;
+
+        if response.status().as_u16() == 401 {
+            return Err(Error::Authentication(response.text().await?));
+        } else if response.status().as_u16() == 404 {
+            return Err(Error::NotFound(response.text().await?));
+        }
+
+        Ok(response.bytes_stream().map_err(Error::from))
+    }
+
+    /// Downloads a byte range of a file from the Storage Zone using the HTTP
+    /// `Range` header, for resuming interrupted transfers or reading slices of
+    /// large objects.
+    ///
+    /// `end` is inclusive; pass `None` for an open-ended `bytes=start-` range
+    /// that runs to the end of the object. A server that honours the request
+    /// answers with `206 Partial Content`; a server that ignores `Range`
+    /// answers with `200 OK` and the full body, which is returned as-is. An
+    /// unsatisfiable range (`416`) surfaces as [`Error::RangeNotSatisfiable`].
+    ///
+    /// ```no_run
+    /// use bunny_api_tokio::{EdgeStorageClient, error::Error, edge_storage::Endpoint};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Error> {
+    ///     let client = EdgeStorageClient::new("storage_zone_api_key", Endpoint::Frankfurt, "MyStorageZone").await?;
+    ///
+    ///     // First kilobyte of the object
+    ///     let head = client.download_range("/images/file.png", 0, Some(1023)).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn download_range<T: AsRef<str>>(
+        &self,
+        path: T,
+        start: u64,
+        end: Option<u64>,
+    ) -> Result<Bytes, Error> {
+        let response = self.range_request(path, start, end).await?;
+
+        Ok(response.bytes().await?)
+    }
+
+    /// Streaming counterpart to [`download_range`](Self::download_range),
+    /// yielding the partial body as a stream of [`Bytes`] chunks.
+    pub async fn download_range_stream<T: AsRef<str>>(
+        &self,
+        path: T,
+        start: u64,
+        end: Option<u64>,
+    ) -> Result<impl Stream<Item = Result<Bytes, Error>>, Error> {
+        let response = self.range_request(path, start, end).await?;
+
+        Ok(response.bytes_stream().map_err(Error::from))
+    }
+
+    /// Issues a `GET` with a `Range` header and maps range-specific statuses
+    /// onto the crate error type, returning the response for the body to be
+    /// read by the caller.
+    async fn range_request<T: AsRef<str>>(
+        &self,
+        path: T,
+        start: u64,
+        end: Option<u64>,
+    ) -> Result<reqwest::Response, Error> {
+        let range = match end {
+            Some(end) => format!("bytes={start}-{end}"),
+            None => format!("bytes={start}-"),
+        };
+
+        let request = self
+            .reqwest
+            .get(self.url.join(path.as_ref())?)
+            .header("accept", "*/*")
+            .header("Range", range);
+
+        let response = self.retry.send(request, true).await?;
+
+        if response.status().as_u16() == 401 {
+            return Err(Error::Authentication(response.text().await?));
+        } else if response.status().as_u16() == 404 {
+            return Err(Error::NotFound(response.text().await?));
+        } else if response.status().as_u16() == 416 {
+            return Err(Error::RangeNotSatisfiable(response.text().await?));
+        }
+
+        Ok(response)
+    }
+
     /// Deletes a file from the Storage Zone
     ///
     /// ```
@@ -145,11 +531,9 @@ impl<'a> EdgeStorageClient {
     /// }
     /// ```
     pub async fn delete<T: AsRef<str>>(&self, path: T) -> Result<(), Error> {
-        let response = self
-            .reqwest
-            .delete(self.url.join(path.as_ref())?)
-            .send()
-            .await?;
+        let request = self.reqwest.delete(self.url.join(path.as_ref())?);
+
+        let response = self.retry.send(request, true).await?;
 
         if response.status().as_u16() == 401 {
             return Err(Error::Authentication(response.text().await?));
@@ -180,11 +564,9 @@ impl<'a> EdgeStorageClient {
     /// }
     /// ```
     pub async fn list<T: AsRef<str>>(&self, path: T) -> Result<Vec<ListFile>, Error> {
-        let response = self
-            .reqwest
-            .get(self.url.join(path.as_ref())?)
-            .send()
-            .await?;
+        let request = self.reqwest.get(self.url.join(path.as_ref())?);
+
+        let response = self.retry.send(request, true).await?;
 
         if response.status().as_u16() == 401 {
             return Err(Error::Authentication(response.text().await?));
@@ -194,4 +576,378 @@ impl<'a> EdgeStorageClient {
 
         Ok(response.json().await?)
     }
+
+    /// Uploads every file under `local_dir` to the Storage Zone concurrently,
+    /// preserving each file's path relative to `local_dir` beneath `prefix`.
+    ///
+    /// Parallelism is bounded by a [`Semaphore`] with `concurrency` permits, and
+    /// per-file results are collected into a [`BulkSummary`] so a single failed
+    /// upload does not abort the rest of the batch. This is the common "sync a
+    /// folder to the CDN" workflow, far cheaper than serial round-trips.
+    ///
+    /// ```no_run
+    /// use bunny_api_tokio::{EdgeStorageClient, error::Error, edge_storage::Endpoint};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Error> {
+    ///     let client = EdgeStorageClient::new("storage_zone_api_key", Endpoint::Frankfurt, "MyStorageZone").await?;
+    ///
+    ///     let summary = client.upload_dir("./site", "/assets", 8).await?;
+    ///
+    ///     println!("uploaded {}, failed {}", summary.succeeded.len(), summary.failed.len());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn upload_dir<P: AsRef<Path>, T: AsRef<str>>(
+        &self,
+        local_dir: P,
+        prefix: T,
+        concurrency: usize,
+    ) -> Result<BulkSummary, Error> {
+        let local_dir = local_dir.as_ref();
+        let prefix = prefix.as_ref().trim_end_matches('/').to_string();
+        let files = collect_local_files(local_dir).await?;
+        let semaphore = Arc::new(Semaphore::new(concurrency.max(1)));
+
+        let tasks = files.into_iter().map(|file| {
+            let semaphore = semaphore.clone();
+            let relative = file
+                .strip_prefix(local_dir)
+                .unwrap_or(&file)
+                .to_string_lossy()
+                .replace('\\', "/");
+            let remote = format!("{prefix}/{relative}");
+
+            async move {
+                let _permit = semaphore.acquire().await.expect("semaphore is never closed");
+                let result = match tokio::fs::read(&file).await {
+                    Ok(bytes) => self.upload(&remote, Bytes::from(bytes)).await,
+                    Err(error) => Err(Error::from(error)),
+                };
+                (remote, result)
+            }
+        });
+
+        Ok(BulkSummary::collect(futures::future::join_all(tasks).await))
+    }
+
+    /// Recursively deletes every object beneath `prefix`, walking into
+    /// subdirectories and deleting their contents concurrently.
+    ///
+    /// As with [`upload_dir`](Self::upload_dir), parallelism is bounded by a
+    /// [`Semaphore`] and per-file failures are collected into the returned
+    /// [`BulkSummary`] rather than aborting the batch.
+    ///
+    /// ```no_run
+    /// use bunny_api_tokio::{EdgeStorageClient, error::Error, edge_storage::Endpoint};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Error> {
+    ///     let client = EdgeStorageClient::new("storage_zone_api_key", Endpoint::Frankfurt, "MyStorageZone").await?;
+    ///
+    ///     let summary = client.delete_prefix("/assets/old/", 8).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn delete_prefix<T: AsRef<str>>(
+        &self,
+        prefix: T,
+        concurrency: usize,
+    ) -> Result<BulkSummary, Error> {
+        let files = self.collect_remote_files(prefix.as_ref()).await?;
+        let semaphore = Arc::new(Semaphore::new(concurrency.max(1)));
+
+        let tasks = files.into_iter().map(|remote| {
+            let semaphore = semaphore.clone();
+            async move {
+                let _permit = semaphore.acquire().await.expect("semaphore is never closed");
+                let result = self.delete(&remote).await;
+                (remote, result)
+            }
+        });
+
+        Ok(BulkSummary::collect(futures::future::join_all(tasks).await))
+    }
+
+    /// Lists `prefix` recursively, returning the full path of every object
+    /// (not directory) found beneath it.
+    async fn collect_remote_files(&self, prefix: &str) -> Result<Vec<String>, Error> {
+        let mut files = Vec::new();
+        let mut directories = vec![prefix.to_string()];
+
+        while let Some(directory) = directories.pop() {
+            let directory = if directory.ends_with('/') {
+                directory
+            } else {
+                format!("{directory}/")
+            };
+
+            for entry in self.list(&directory).await? {
+                let child = format!("{}{}", directory, entry.object_name);
+                if entry.is_directory {
+                    directories.push(child);
+                } else {
+                    files.push(child);
+                }
+            }
+        }
+
+        Ok(files)
+    }
+
+    /// Lists `path` recursively, descending into every entry whose
+    /// [`ListFile::is_directory`] is `true` and returning the flattened set of
+    /// file entries found beneath it.
+    ///
+    /// Directories at each level are listed with bounded concurrency and paths
+    /// are de-duplicated, so an entry reported more than once is returned and
+    /// descended into only once. Cycles are not detected: a listing that keeps
+    /// reporting a subdirectory of itself is walked level by level without end.
+    ///
+    /// ```no_run
+    /// use bunny_api_tokio::{EdgeStorageClient, error::Error, edge_storage::Endpoint};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Error> {
+    ///     let client = EdgeStorageClient::new("storage_zone_api_key", Endpoint::Frankfurt, "MyStorageZone").await?;
+    ///
+    ///     let files = client.list_recursive("/images/").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn list_recursive<T: AsRef<str>>(&self, path: T) -> Result<Vec<ListFile>, Error> {
+        let entries = self.remote_entries(path.as_ref()).await?;
+        Ok(entries.into_iter().map(|(_, file)| file).collect())
+    }
+
+    /// Mirrors `local_dir` onto the Storage Zone beneath `remote_prefix`,
+    /// uploading only files whose contents differ from what is already stored.
+    ///
+    /// The remote side is discovered with [`list_recursive`](Self::list_recursive)
+    /// and each local file is compared against it using `length` and the
+    /// server-side `checksum`; unchanged files are skipped. When
+    /// `delete_orphans` is set, remote files with no local counterpart are
+    /// deleted. Per-file failures are collected into the returned
+    /// [`SyncSummary`] rather than aborting the sync.
+    ///
+    /// ```no_run
+    /// use bunny_api_tokio::{EdgeStorageClient, error::Error, edge_storage::Endpoint};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Error> {
+    ///     let client = EdgeStorageClient::new("storage_zone_api_key", Endpoint::Frankfurt, "MyStorageZone").await?;
+    ///
+    ///     let summary = client.sync_dir("./site", "/assets", true).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn sync_dir<P: AsRef<Path>, T: AsRef<str>>(
+        &self,
+        local_dir: P,
+        remote_prefix: T,
+        delete_orphans: bool,
+    ) -> Result<SyncSummary, Error> {
+        let local_dir = local_dir.as_ref();
+        let prefix = remote_prefix.as_ref().trim_end_matches('/').to_string();
+
+        let remote: HashMap<String, ListFile> =
+            self.remote_entries(&prefix).await?.into_iter().collect();
+
+        let local_files = collect_local_files(local_dir).await?;
+        let semaphore = Arc::new(Semaphore::new(SYNC_CONCURRENCY));
+        let mut summary = SyncSummary::default();
+        let mut present = HashSet::new();
+
+        let uploads = local_files.into_iter().map(|file| {
+            let semaphore = semaphore.clone();
+            let relative = file
+                .strip_prefix(local_dir)
+                .unwrap_or(&file)
+                .to_string_lossy()
+                .replace('\\', "/");
+            let existing = remote.get(&relative).map(|f| (f.length, f.checksum.to_uppercase()));
+            let remote_path = format!("{prefix}/{relative}");
+
+            async move {
+                let _permit = semaphore.acquire().await.expect("semaphore is never closed");
+                let bytes = match tokio::fs::read(&file).await {
+                    Ok(bytes) => bytes,
+                    Err(error) => return (relative, remote_path, Err(Error::from(error))),
+                };
+
+                let unchanged = matches!(
+                    existing,
+                    Some((length, ref checksum))
+                        if length as usize == bytes.len() && *checksum == sha256_hex(&bytes)
+                );
+
+                if unchanged {
+                    return (relative, remote_path, Ok(false));
+                }
+
+                let result = self.upload(&remote_path, Bytes::from(bytes)).await.map(|()| true);
+                (relative, remote_path, result)
+            }
+        });
+
+        for (relative, remote_path, result) in futures::future::join_all(uploads).await {
+            present.insert(relative);
+            match result {
+                Ok(true) => summary.uploaded.push(remote_path),
+                Ok(false) => summary.unchanged.push(remote_path),
+                Err(error) => summary.failed.push((remote_path, error)),
+            }
+        }
+
+        if delete_orphans {
+            let orphans = remote
+                .into_iter()
+                .filter(|(relative, _)| !present.contains(relative))
+                .map(|(relative, _)| format!("{prefix}/{relative}"));
+
+            let deletions = orphans.map(|remote_path| {
+                let semaphore = semaphore.clone();
+                async move {
+                    let _permit = semaphore.acquire().await.expect("semaphore is never closed");
+                    let result = self.delete(&remote_path).await;
+                    (remote_path, result)
+                }
+            });
+
+            for (remote_path, result) in futures::future::join_all(deletions).await {
+                match result {
+                    Ok(()) => summary.deleted.push(remote_path),
+                    Err(error) => summary.failed.push((remote_path, error)),
+                }
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Lists `prefix` recursively, returning each file entry paired with its
+    /// path relative to `prefix` (for example `sub/file.png`).
+    async fn remote_entries(&self, prefix: &str) -> Result<Vec<(String, ListFile)>, Error> {
+        let base = if prefix.is_empty() || prefix.ends_with('/') {
+            prefix.to_string()
+        } else {
+            format!("{prefix}/")
+        };
+
+        let mut files = Vec::new();
+        let mut seen = HashSet::new();
+        let mut frontier = vec![(String::new(), base)];
+
+        while !frontier.is_empty() {
+            let listings = futures::stream::iter(frontier.drain(..).map(|(relative, directory)| {
+                let directory = directory.clone();
+                async move {
+                    let result = self.list(&directory).await;
+                    (relative, directory, result)
+                }
+            }))
+            .buffer_unordered(SYNC_CONCURRENCY)
+            .collect::<Vec<_>>()
+            .await;
+
+            let mut next = Vec::new();
+            for (relative, directory, result) in listings {
+                for entry in result? {
+                    let child_relative = format!("{relative}{}", entry.object_name);
+                    if !seen.insert(child_relative.clone()) {
+                        continue;
+                    }
+
+                    if entry.is_directory {
+                        next.push((format!("{child_relative}/"), format!("{directory}{}/", entry.object_name)));
+                    } else {
+                        files.push((child_relative, entry));
+                    }
+                }
+            }
+
+            frontier = next;
+        }
+
+        Ok(files)
+    }
+}
+
+/// Maximum number of concurrent requests issued by the recursive-listing and
+/// directory-sync helpers.
+const SYNC_CONCURRENCY: usize = 8;
+
+/// Summary of a [`sync_dir`](EdgeStorageClient::sync_dir) run.
+#[derive(Debug, Default)]
+pub struct SyncSummary {
+    /// Remote paths that were uploaded because they were new or changed.
+    pub uploaded: Vec<String>,
+    /// Remote paths left untouched because they already matched locally.
+    pub unchanged: Vec<String>,
+    /// Remote orphan paths that were deleted (empty unless `delete_orphans`).
+    pub deleted: Vec<String>,
+    /// Remote paths that failed, paired with the error that occurred.
+    pub failed: Vec<(String, Error)>,
+}
+
+/// Summary of a concurrent bulk operation such as
+/// [`upload_dir`](EdgeStorageClient::upload_dir) or
+/// [`delete_prefix`](EdgeStorageClient::delete_prefix).
+#[derive(Debug, Default)]
+pub struct BulkSummary {
+    /// Remote paths that completed successfully.
+    pub succeeded: Vec<String>,
+    /// Remote paths that failed, paired with the error that occurred.
+    pub failed: Vec<(String, Error)>,
+}
+
+impl BulkSummary {
+    /// Splits the per-file results of a bulk operation into successes and
+    /// failures.
+    fn collect(results: Vec<(String, Result<(), Error>)>) -> Self {
+        let mut summary = BulkSummary::default();
+        for (path, result) in results {
+            match result {
+                Ok(()) => summary.succeeded.push(path),
+                Err(error) => summary.failed.push((path, error)),
+            }
+        }
+        summary
+    }
+}
+
+/// Walks `root` recursively and returns the path of every file beneath it.
+async fn collect_local_files(root: &Path) -> Result<Vec<PathBuf>, Error> {
+    let mut files = Vec::new();
+    let mut directories = vec![root.to_path_buf()];
+
+    while let Some(directory) = directories.pop() {
+        let mut entries = tokio::fs::read_dir(&directory).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if entry.file_type().await?.is_dir() {
+                directories.push(path);
+            } else {
+                files.push(path);
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+/// Computes the SHA256 digest of `bytes` as an uppercase hex string, the format
+/// Bunny's Edge Storage expects in the `Checksum` header.
+fn sha256_hex(bytes: &[u8]) -> String {
+    let digest = Sha256::digest(bytes);
+    let mut hex = String::with_capacity(digest.len() * 2);
+    for byte in digest {
+        use std::fmt::Write;
+        let _ = write!(hex, "{byte:02X}");
+    }
+    hex
 }
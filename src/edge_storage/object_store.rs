@@ -0,0 +1,231 @@
+//! [`object_store::ObjectStore`] implementation for [`EdgeStorageClient`].
+//!
+//! Gated behind the `object_store` feature. Mapping Bunny's Edge Storage
+//! endpoints onto the trait lets storage zones drop into the wider ecosystem
+//! built on top of it (DataFusion, Parquet readers, and so on) without anyone
+//! hand-rolling the glue.
+
+use std::ops::Range;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
+use object_store::{
+    path::Path, GetOptions, GetRange, GetResult, GetResultPayload, ListResult, MultipartUpload,
+    ObjectMeta, ObjectStore, PutMultipartOpts, PutOptions, PutPayload, PutResult, Result as OsResult,
+};
+
+use super::{EdgeStorageClient, ListFile};
+use crate::error::Error;
+
+impl From<Error> for object_store::Error {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::NotFound(source) => object_store::Error::NotFound {
+                path: String::new(),
+                source: source.into(),
+            },
+            Error::Authentication(source) | Error::BadRequest(source) => {
+                object_store::Error::Generic {
+                    store: "bunny",
+                    source: source.into(),
+                }
+            }
+            other => object_store::Error::Generic {
+                store: "bunny",
+                source: Box::new(other),
+            },
+        }
+    }
+}
+
+impl EdgeStorageClient {
+    /// Converts a [`ListFile`] into an [`object_store::ObjectMeta`].
+    fn object_meta(file: &ListFile) -> OsResult<ObjectMeta> {
+        let location = Path::from(format!("{}{}", file.path, file.object_name));
+        let last_modified = file
+            .last_changed
+            .parse()
+            .map_err(|_| object_store::Error::Generic {
+                store: "bunny",
+                source: format!("invalid LastChanged timestamp: {}", file.last_changed).into(),
+            })?;
+
+        Ok(ObjectMeta {
+            location,
+            last_modified,
+            size: file.length as u64,
+            e_tag: Some(file.checksum.clone()),
+            version: None,
+        })
+    }
+
+    /// Fetches the total size of an object with a `HEAD` request, used to
+    /// populate [`ObjectMeta::size`] and to resolve suffix ranges.
+    async fn head_size(&self, path: &str) -> Result<u64, Error> {
+        let request = self.reqwest.head(self.url.join(path)?);
+
+        let response = self.retry.send(request, true).await?;
+
+        if response.status().as_u16() == 401 {
+            return Err(Error::Authentication(response.text().await?));
+        } else if response.status().as_u16() == 404 {
+            return Err(Error::NotFound(response.text().await?));
+        }
+
+        // Read the header itself: `content_length()` reports the size of the
+        // (empty) HEAD body rather than the object's size.
+        response
+            .headers()
+            .get(reqwest::header::CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok())
+            .ok_or_else(|| Error::BadRequest(String::from("HEAD response is missing Content-Length")))
+    }
+}
+
+#[async_trait]
+impl ObjectStore for EdgeStorageClient {
+    async fn put_opts(
+        &self,
+        location: &Path,
+        payload: PutPayload,
+        _opts: PutOptions,
+    ) -> OsResult<PutResult> {
+        let bytes: Bytes = payload.into();
+        self.upload(location.as_ref(), bytes).await?;
+
+        Ok(PutResult {
+            e_tag: None,
+            version: None,
+        })
+    }
+
+    async fn put_multipart_opts(
+        &self,
+        _location: &Path,
+        _opts: PutMultipartOpts,
+    ) -> OsResult<Box<dyn MultipartUpload>> {
+        Err(object_store::Error::NotImplemented)
+    }
+
+    async fn get_opts(&self, location: &Path, options: GetOptions) -> OsResult<GetResult> {
+        let (bytes, range, size) = match options.range {
+            Some(GetRange::Bounded(requested)) => {
+                let size = self.head_size(location.as_ref()).await?;
+                // Clamp the requested end to the object size so the reported
+                // range never exceeds the bytes actually streamed.
+                let end = requested.end.min(size);
+                if requested.start >= end {
+                    (Bytes::new(), requested.start..requested.start, size)
+                } else {
+                    let bytes = self
+                        .download_range(location.as_ref(), requested.start, Some(end - 1))
+                        .await?;
+                    (bytes, requested.start..end, size)
+                }
+            }
+            Some(GetRange::Offset(start)) => {
+                let size = self.head_size(location.as_ref()).await?;
+                let bytes = self
+                    .download_range(location.as_ref(), start, None)
+                    .await?;
+                (bytes, start..size, size)
+            }
+            Some(GetRange::Suffix(n)) => {
+                let size = self.head_size(location.as_ref()).await?;
+                let start = size.saturating_sub(n);
+                let bytes = self
+                    .download_range(location.as_ref(), start, None)
+                    .await?;
+                (bytes, start..size, size)
+            }
+            None => {
+                // Full-object read: the size falls out of the body, so no extra
+                // HEAD round-trip is needed.
+                let bytes = self.download(location.as_ref()).await?;
+                let size = bytes.len() as u64;
+                (bytes, 0..size, size)
+            }
+        };
+
+        let meta = ObjectMeta {
+            location: location.clone(),
+            last_modified: Default::default(),
+            size,
+            e_tag: None,
+            version: None,
+        };
+
+        let payload = GetResultPayload::Stream(stream::once(async move { Ok(bytes) }).boxed());
+
+        Ok(GetResult {
+            payload,
+            meta,
+            range,
+            attributes: Default::default(),
+        })
+    }
+
+    async fn delete(&self, location: &Path) -> OsResult<()> {
+        self.delete(location.as_ref()).await?;
+        Ok(())
+    }
+
+    fn list(&self, prefix: Option<&Path>) -> BoxStream<'static, OsResult<ObjectMeta>> {
+        let client = self.clone();
+        // Keep the prefix relative so `self.url.join` preserves the storage-zone
+        // segment; a leading slash would be treated as an absolute path and
+        // drop the zone.
+        let prefix = prefix.map(|p| format!("{}/", p.as_ref())).unwrap_or_default();
+
+        stream::once(async move { client.list(prefix).await })
+            .map_err(object_store::Error::from)
+            .map_ok(|files| {
+                stream::iter(
+                    files
+                        .into_iter()
+                        .filter(|file| !file.is_directory)
+                        .map(|file| EdgeStorageClient::object_meta(&file)),
+                )
+            })
+            .try_flatten()
+            .boxed()
+    }
+
+    async fn list_with_delimiter(&self, prefix: Option<&Path>) -> OsResult<ListResult> {
+        let prefix_path = prefix
+            .map(|p| format!("{}/", p.as_ref()))
+            .unwrap_or_default();
+        let files = self.list(prefix_path).await?;
+
+        let mut objects = Vec::new();
+        let mut common_prefixes = Vec::new();
+        for file in &files {
+            if file.is_directory {
+                common_prefixes.push(Path::from(format!("{}{}", file.path, file.object_name)));
+            } else {
+                objects.push(EdgeStorageClient::object_meta(file)?);
+            }
+        }
+
+        Ok(ListResult {
+            common_prefixes,
+            objects,
+        })
+    }
+
+    async fn copy(&self, _from: &Path, _to: &Path) -> OsResult<()> {
+        Err(object_store::Error::NotImplemented)
+    }
+
+    async fn copy_if_not_exists(&self, _from: &Path, _to: &Path) -> OsResult<()> {
+        Err(object_store::Error::NotImplemented)
+    }
+}
+
+impl std::fmt::Display for EdgeStorageClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BunnyEdgeStorage({})", self.url)
+    }
+}
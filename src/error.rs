@@ -26,4 +26,21 @@ pub enum Error {
     /// Not found error
     #[error("not found: {0}")]
     NotFound(String),
+
+    /// Requested byte range could not be satisfied by the server
+    #[error("range not satisfiable: {0}")]
+    RangeNotSatisfiable(String),
+
+    /// Filesystem error while reading or writing a local file
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// SHA256 checksum of the transferred bytes did not match the expected value
+    #[error("checksum mismatch: expected {expected}, got {actual}")]
+    ChecksumMismatch {
+        /// The checksum that was expected
+        expected: String,
+        /// The checksum that was actually computed
+        actual: String,
+    },
 }
@@ -28,3 +28,5 @@ pub mod edge_storage;
 #[cfg(feature = "edge_storage")]
 pub use edge_storage::EdgeStorageClient;
 pub mod error;
+mod retry;
+pub use retry::RetryConfig;
@@ -0,0 +1,139 @@
+//! Retry layer shared by [`BunnyClient`](crate::BunnyClient) and
+//! [`EdgeStorageClient`](crate::EdgeStorageClient).
+//!
+//! Transient failures — connection errors, `408`, `429 Too Many Requests` and
+//! `5xx` responses — are retried with exponential backoff and full jitter.
+//! A `Retry-After` header is honoured when present.
+
+use std::time::{Duration, SystemTime};
+
+use rand::Rng;
+use reqwest::{RequestBuilder, Response, StatusCode};
+
+use crate::error::Error;
+
+/// Configuration for the built-in retry layer.
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    /// Maximum number of retries after the initial attempt.
+    pub max_retries: u32,
+    /// Base delay used for the exponential backoff.
+    pub base_delay: Duration,
+    /// Upper bound on a single backoff delay.
+    pub max_delay: Duration,
+    /// Whether to honour a `Retry-After` header when present.
+    pub respect_retry_after: bool,
+    /// Whether non-idempotent operations (`upload`, `purge_url`) may be retried.
+    /// Off by default so retries never double-apply side effects.
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(30),
+            respect_retry_after: true,
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// A configuration that disables retries entirely.
+    pub fn disabled() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Sends `request`, retrying transient failures according to this config.
+    ///
+    /// `idempotent` gates whether a retry is allowed to resend a request with
+    /// side effects; when `false`, retries only happen if
+    /// [`retry_non_idempotent`](Self::retry_non_idempotent) is set. Requests
+    /// whose body cannot be cloned (for example a stream) are never retried.
+    pub async fn send(&self, request: RequestBuilder, idempotent: bool) -> Result<Response, Error> {
+        let retries_allowed = idempotent || self.retry_non_idempotent;
+
+        let mut attempt: u32 = 0;
+        loop {
+            let try_request = if retries_allowed && attempt < self.max_retries {
+                request.try_clone()
+            } else {
+                None
+            };
+
+            let response = match try_request {
+                // A clone is available, so this attempt can be retried.
+                Some(cloned) => cloned.send().await,
+                // Final attempt (or an unclonable body): consume the builder.
+                None => return request.send().await.map_err(Error::from),
+            };
+
+            match response {
+                Ok(response) if is_retryable_status(response.status()) => {
+                    let delay = self.delay_for(attempt, &response);
+                    tokio::time::sleep(delay).await;
+                }
+                Ok(response) => return Ok(response),
+                Err(error) if is_retryable_error(&error) => {
+                    tokio::time::sleep(self.backoff(attempt)).await;
+                }
+                Err(error) => return Err(error.into()),
+            }
+
+            attempt += 1;
+        }
+    }
+
+    /// Picks the delay before the next attempt, preferring `Retry-After`.
+    fn delay_for(&self, attempt: u32, response: &Response) -> Duration {
+        if self.respect_retry_after {
+            if let Some(retry_after) = parse_retry_after(response) {
+                return retry_after.max(self.backoff(attempt));
+            }
+        }
+        self.backoff(attempt)
+    }
+
+    /// Exponential backoff with full jitter: a uniform random value in
+    /// `[0, min(max_delay, base_delay * 2^attempt)]`.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exponential = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt));
+        let capped = exponential.min(self.max_delay);
+        let millis = capped.as_millis() as u64;
+        Duration::from_millis(rand::thread_rng().gen_range(0..=millis))
+    }
+}
+
+/// Whether a status code should be retried.
+fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::REQUEST_TIMEOUT
+        || status == StatusCode::TOO_MANY_REQUESTS
+        || status.is_server_error()
+}
+
+/// Whether a transport-level error (connect, timeout or a failure while
+/// sending the request) should be retried.
+fn is_retryable_error(error: &reqwest::Error) -> bool {
+    error.is_connect() || error.is_timeout() || error.is_request()
+}
+
+/// Parses a `Retry-After` header, accepting either a number of seconds or an
+/// HTTP-date.
+fn parse_retry_after(response: &Response) -> Option<Duration> {
+    let value = response.headers().get(reqwest::header::RETRY_AFTER)?;
+    let value = value.to_str().ok()?;
+
+    if let Ok(seconds) = value.trim().parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let when = httpdate::parse_http_date(value).ok()?;
+    when.duration_since(SystemTime::now()).ok()
+}